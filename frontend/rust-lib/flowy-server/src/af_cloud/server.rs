@@ -1,10 +1,12 @@
-use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 use std::sync::{Arc, Weak};
 use std::time::Duration;
 
 use crate::af_cloud::define::LoggedUser;
 use anyhow::Error;
 use arc_swap::ArcSwap;
+use base64::Engine;
+use chrono::{DateTime, Utc};
 use client_api::collab_sync::ServerCollabMessage;
 use client_api::entity::UserMessage;
 use client_api::notify::{TokenState, TokenStateReceiver};
@@ -12,6 +14,7 @@ use client_api::ws::{
   ConnectState, WSClient, WSClientConfig, WSConnectStateReceiver, WebSocketChannel,
 };
 use client_api::{Client, ClientConfiguration};
+use dashmap::DashMap;
 
 use flowy_ai_pub::cloud::ChatCloudService;
 use flowy_database_pub::cloud::{DatabaseAIService, DatabaseCloudService};
@@ -37,7 +40,7 @@ use lib_infra::async_trait::async_trait;
 use rand::Rng;
 use semver::Version;
 use tokio::select;
-use tokio::sync::{RwLock, watch};
+use tokio::sync::{RwLock, broadcast, watch};
 use tokio::task::JoinHandle;
 use tokio_stream::wrappers::WatchStream;
 use tokio_util::sync::CancellationToken;
@@ -46,6 +49,135 @@ use uuid::Uuid;
 
 pub(crate) type AFCloudClient = Client;
 
+/// Per-connection registry of the collab websocket channels handed out by [`AppFlowyCloudServer::collab_ws_channel`],
+/// keyed by `object_id`. Entries are weak so a dropped subscriber is naturally pruned the next
+/// time the registry is walked, without the registry itself keeping channels alive.
+type CollabSubscriptionRegistry = DashMap<String, Weak<WebSocketChannel<ServerCollabMessage>>>;
+
+/// Fraction of a token's remaining lifetime to wait before proactively refreshing it.
+const PROACTIVE_REFRESH_LIFETIME_FRACTION: f64 = 0.8;
+/// Always leave at least this much time before expiry, even for short-lived tokens.
+const PROACTIVE_REFRESH_MIN_LEAD: Duration = Duration::from_secs(30);
+
+#[derive(serde::Deserialize)]
+struct JwtClaims {
+  exp: i64,
+}
+
+/// Reads the `exp` claim out of a JWT without verifying its signature; the signature is already
+/// verified server-side, this is only used to schedule a local, client-side refresh.
+fn jwt_expires_at(token: &str) -> Option<DateTime<Utc>> {
+  let payload = token.split('.').nth(1)?;
+  let bytes = base64::engine::general_purpose::URL_SAFE_NO_PAD
+    .decode(payload)
+    .ok()?;
+  let claims: JwtClaims = serde_json::from_slice(&bytes).ok()?;
+  DateTime::from_timestamp(claims.exp, 0)
+}
+
+/// Tunables for the reconnect backoff used by [`attempt_reconnect`]. Defaults are set here;
+/// embedders can trade off reconnect latency against load on the server during an outage via
+/// [`AppFlowyCloudServer::set_reconnect_backoff_config`].
+#[derive(Debug, Clone, Copy)]
+pub struct ReconnectBackoffConfig {
+  /// Delay used for the first retry, before any jitter is applied.
+  pub base: Duration,
+  /// Upper bound the exponential delay is clamped to.
+  pub cap: Duration,
+  /// Stop retrying automatically after this many consecutive failures. `None` means retry forever.
+  pub max_attempts: Option<u32>,
+}
+
+impl Default for ReconnectBackoffConfig {
+  fn default() -> Self {
+    Self {
+      base: Duration::from_secs(1),
+      cap: Duration::from_secs(300),
+      max_attempts: None,
+    }
+  }
+}
+
+/// Who drives reconnection after the websocket drops. Defaults to `Automatic`; switch it at
+/// runtime via [`AppFlowyCloudServer::set_reconnect_policy`]. The backoff timing `Automatic` uses
+/// is a separate knob, tuned via [`AppFlowyCloudServer::set_reconnect_backoff_config`].
+///
+/// `Manual` lets the embedding app own reconnection policy (e.g. suppressing background retries on
+/// a metered network and only reconnecting in the foreground) instead of retrying automatically.
+#[derive(Debug, Clone, Copy, Default)]
+pub enum ReconnectPolicy {
+  #[default]
+  Automatic,
+  Manual,
+}
+
+/// Emitted on [`AppFlowyCloudServer::subscribe_reconnect_events`] when [`ReconnectPolicy::Manual`]
+/// is active and the websocket drops, so the embedder can decide when (or whether) to call
+/// [`AppFlowyCloudServer::reconnect_now`].
+#[derive(Debug, Clone)]
+pub enum ReconnectEvent {
+  ReconnectRequested { reason: DisconnectReason },
+}
+
+/// The reason the websocket most recently dropped, as observed from `WSClient`'s connect-state
+/// stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DisconnectReason {
+  Lost,
+  PingTimeout,
+  Unauthorized,
+}
+
+/// A point-in-time snapshot of the websocket connection's health, for "last synced" indicators
+/// and flapping-connection alerts. Obtained via [`AppFlowyCloudServer::connection_health`] or
+/// streamed through [`AppFlowyCloudServer::subscribe_connection_health`].
+#[derive(Debug, Clone)]
+pub struct ConnectionHealth {
+  pub state: ConnectState,
+  pub last_connected_at: Option<DateTime<Utc>>,
+  pub last_disconnected_at: Option<DateTime<Utc>>,
+  pub last_disconnect_reason: Option<DisconnectReason>,
+  pub consecutive_reconnect_failures: u32,
+  pub total_reconnect_attempts: u64,
+}
+
+impl ConnectionHealth {
+  fn new(state: ConnectState) -> Self {
+    Self {
+      state,
+      last_connected_at: None,
+      last_disconnected_at: None,
+      last_disconnect_reason: None,
+      consecutive_reconnect_failures: 0,
+      total_reconnect_attempts: 0,
+    }
+  }
+}
+
+/// Tracks in-flight reconnect state shared between the connect-state loop and the token-state loop.
+struct ReconnectState {
+  cancellation_token: ArcSwap<CancellationToken>,
+  /// Number of consecutive failed reconnect attempts, reset to 0 on a successful `connect()`.
+  consecutive_failures: AtomicU32,
+  health_tx: watch::Sender<ConnectionHealth>,
+}
+
+impl ReconnectState {
+  fn new(initial_state: ConnectState) -> (Arc<Self>, watch::Receiver<ConnectionHealth>) {
+    let (health_tx, health_rx) = watch::channel(ConnectionHealth::new(initial_state));
+    let state = Arc::new(Self {
+      cancellation_token: ArcSwap::new(Arc::new(CancellationToken::new())),
+      consecutive_failures: AtomicU32::new(0),
+      health_tx,
+    });
+    (state, health_rx)
+  }
+
+  fn update_health(&self, f: impl FnOnce(&mut ConnectionHealth)) {
+    self.health_tx.send_modify(f);
+  }
+}
+
 pub struct AppFlowyCloudServer {
   #[allow(dead_code)]
   pub(crate) config: AFCloudConfiguration,
@@ -57,6 +189,12 @@ pub struct AppFlowyCloudServer {
   logged_user: Weak<dyn LoggedUser>,
   ai_user_service: Arc<dyn AIUserService>,
   tanvity_state: RwLock<Option<Weak<RwLock<DocumentTantivyState>>>>,
+  connection_health_rx: watch::Receiver<ConnectionHealth>,
+  collab_subscriptions: Arc<CollabSubscriptionRegistry>,
+  reconnect_state: Arc<ReconnectState>,
+  reconnect_events_tx: broadcast::Sender<ReconnectEvent>,
+  reconnect_policy: Arc<ArcSwap<ReconnectPolicy>>,
+  backoff_config: Arc<ArcSwap<ReconnectBackoffConfig>>,
 }
 
 impl AppFlowyCloudServer {
@@ -85,17 +223,33 @@ impl AppFlowyCloudServer {
       &client_version.to_string(),
     );
     let token_state_rx = api_client.subscribe_token_state();
+    let proactive_refresh_token_state_rx = api_client.subscribe_token_state();
     let enable_sync = Arc::new(AtomicBool::new(enable_sync));
     let network_reachable = Arc::new(AtomicBool::new(true));
 
-    let ws_client = WSClient::new(
-      WSClientConfig::default(),
-      api_client.clone(),
-      api_client.clone(),
-    );
+    // `WSClientConfig`'s own reconnect knobs, if any, go unused here: reconnection is driven
+    // entirely by `attempt_reconnect` below, so the tunables live on `ReconnectBackoffConfig`
+    // and `ReconnectPolicy` instead of on the client_api-owned config type.
+    let backoff_config = Arc::new(ArcSwap::new(Arc::new(ReconnectBackoffConfig::default())));
+    let ws_client = WSClient::new(WSClientConfig::default(), api_client.clone(), api_client.clone());
     let ws_client = Arc::new(ws_client);
     let api_client = Arc::new(api_client);
-    spawn_ws_conn(token_state_rx, &ws_client, &api_client, &enable_sync);
+    let (reconnect_state, connection_health_rx) = ReconnectState::new(ws_client.get_state());
+    let collab_subscriptions: Arc<CollabSubscriptionRegistry> = Arc::new(DashMap::new());
+    let reconnect_policy = Arc::new(ArcSwap::new(Arc::new(ReconnectPolicy::default())));
+    let (reconnect_events_tx, _) = broadcast::channel(16);
+    spawn_ws_conn(
+      token_state_rx,
+      &ws_client,
+      &api_client,
+      &enable_sync,
+      backoff_config.clone(),
+      reconnect_state.clone(),
+      collab_subscriptions.clone(),
+      reconnect_policy.clone(),
+      reconnect_events_tx.clone(),
+    );
+    spawn_proactive_token_refresh(&api_client, &enable_sync, proactive_refresh_token_state_rx);
 
     Self {
       config,
@@ -107,6 +261,12 @@ impl AppFlowyCloudServer {
       logged_user,
       ai_user_service,
       tanvity_state: Default::default(),
+      connection_health_rx,
+      reconnect_state,
+      reconnect_events_tx,
+      collab_subscriptions,
+      reconnect_policy,
+      backoff_config,
     }
   }
 
@@ -118,6 +278,81 @@ impl AppFlowyCloudServer {
     };
     AFServerImpl { client }
   }
+
+  /// Returns a snapshot of the current websocket connection health, for "last synced" indicators
+  /// and flapping-connection alerts.
+  pub fn connection_health(&self) -> ConnectionHealth {
+    self.connection_health_rx.borrow().clone()
+  }
+
+  /// Streams [`ConnectionHealth`] updates as they happen, in addition to the raw `ConnectState`
+  /// available through [`AppFlowyServer::subscribe_ws_state`].
+  pub fn subscribe_connection_health(&self) -> WatchStream<ConnectionHealth> {
+    WatchStream::new(self.connection_health_rx.clone())
+  }
+
+  /// Subscribes to [`ReconnectEvent`]s. Only fires while [`ReconnectPolicy::Manual`] is active;
+  /// under the default automatic policy reconnection is handled internally and nothing is sent.
+  pub fn subscribe_reconnect_events(&self) -> broadcast::Receiver<ReconnectEvent> {
+    self.reconnect_events_tx.subscribe()
+  }
+
+  /// Returns the currently active [`ReconnectPolicy`].
+  pub fn reconnect_policy(&self) -> ReconnectPolicy {
+    *self.reconnect_policy.load_full()
+  }
+
+  /// Switches between [`ReconnectPolicy::Automatic`] and [`ReconnectPolicy::Manual`] at runtime,
+  /// e.g. so an embedder can suppress background retries on a metered network and switch back to
+  /// automatic reconnection once the app is foregrounded.
+  pub fn set_reconnect_policy(&self, policy: ReconnectPolicy) {
+    self.reconnect_policy.store(Arc::new(policy));
+  }
+
+  /// Returns the [`ReconnectBackoffConfig`] currently used by [`attempt_reconnect`].
+  pub fn reconnect_backoff_config(&self) -> ReconnectBackoffConfig {
+    *self.backoff_config.load_full()
+  }
+
+  /// Tunes the reconnect backoff's `base`, `cap`, and `max_attempts` at runtime, e.g. to back off
+  /// less aggressively on a connection known to be reliable.
+  pub fn set_reconnect_backoff_config(&self, config: ReconnectBackoffConfig) {
+    self.backoff_config.store(Arc::new(config));
+  }
+
+  /// Performs a single guarded reconnect attempt, cancelling any pending automatic retry first.
+  ///
+  /// Intended for embedders using [`ReconnectPolicy::Manual`] to trigger reconnection on
+  /// foreground or user action, but safe to call regardless of policy.
+  pub async fn reconnect_now(&self) -> Result<(), Error> {
+    self.reconnect_state.cancellation_token.load_full().cancel();
+    match self.ws_client.connect().await {
+      Ok(_) => {
+        self
+          .reconnect_state
+          .consecutive_failures
+          .store(0, Ordering::SeqCst);
+        self.reconnect_state.update_health(|health| {
+          health.total_reconnect_attempts += 1;
+          health.consecutive_reconnect_failures = 0;
+        });
+        resubscribe_live_channels(&self.ws_client, &self.collab_subscriptions);
+        Ok(())
+      },
+      Err(err) => {
+        let failures = self
+          .reconnect_state
+          .consecutive_failures
+          .fetch_add(1, Ordering::SeqCst)
+          + 1;
+        self.reconnect_state.update_health(|health| {
+          health.total_reconnect_attempts += 1;
+          health.consecutive_reconnect_failures = failures;
+        });
+        Err(anyhow::anyhow!("Failed to reconnect websocket: {}", err))
+      },
+    }
+  }
 }
 
 #[async_trait]
@@ -254,7 +489,12 @@ impl AppFlowyServer for AppFlowyCloudServer {
     Error,
   > {
     let object_id = _object_id.to_string();
-    let channel = self.ws_client.subscribe_collab(object_id).ok();
+    let channel = self.ws_client.subscribe_collab(object_id.clone()).ok();
+    if let Some(channel) = &channel {
+      self
+        .collab_subscriptions
+        .insert(object_id, Arc::downgrade(channel));
+    }
     let connect_state_recv = self.ws_client.subscribe_connect_state();
     Ok(channel.map(|c| (c, connect_state_recv, self.ws_client.is_connected())))
   }
@@ -288,35 +528,83 @@ fn spawn_ws_conn(
   ws_client: &Arc<WSClient>,
   api_client: &Arc<Client>,
   enable_sync: &Arc<AtomicBool>,
+  backoff_config: Arc<ArcSwap<ReconnectBackoffConfig>>,
+  reconnect_state: Arc<ReconnectState>,
+  collab_subscriptions: Arc<CollabSubscriptionRegistry>,
+  reconnect_policy: Arc<ArcSwap<ReconnectPolicy>>,
+  reconnect_events_tx: broadcast::Sender<ReconnectEvent>,
 ) {
   let weak_ws_client = Arc::downgrade(ws_client);
   let weak_api_client = Arc::downgrade(api_client);
   let enable_sync = enable_sync.clone();
 
-  let cancellation_token = Arc::new(ArcSwap::new(Arc::new(CancellationToken::new())));
-  let cloned_cancellation_token = cancellation_token.clone();
+  let cloned_reconnect_state = reconnect_state.clone();
+  let cloned_collab_subscriptions = collab_subscriptions.clone();
+  let cloned_backoff_config = backoff_config.clone();
 
   tokio::spawn(async move {
     if let Some(ws_client) = weak_ws_client.upgrade() {
       let mut state_recv = ws_client.subscribe_connect_state();
       while let Ok(state) = state_recv.recv().await {
         info!("[websocket] state: {:?}", state);
+        let disconnect_reason = match state {
+          ConnectState::Lost => Some(DisconnectReason::Lost),
+          ConnectState::PingTimeout => Some(DisconnectReason::PingTimeout),
+          ConnectState::Unauthorized => Some(DisconnectReason::Unauthorized),
+          _ => None,
+        };
+        let is_connected = ws_client.is_connected();
+        cloned_reconnect_state.update_health(|health| {
+          health.state = state;
+          if is_connected {
+            health.last_connected_at = Some(Utc::now());
+          }
+          if let Some(reason) = disconnect_reason {
+            health.last_disconnected_at = Some(Utc::now());
+            health.last_disconnect_reason = Some(reason);
+          }
+        });
+
         match state {
-          ConnectState::PingTimeout | ConnectState::Lost => {
-            // Try to reconnect if the connection is timed out.
-            if weak_api_client.upgrade().is_some() && enable_sync.load(Ordering::SeqCst) {
-              attempt_reconnect(&ws_client, 2, &cloned_cancellation_token).await;
-            }
+          ConnectState::PingTimeout | ConnectState::Lost => match *reconnect_policy.load_full() {
+            ReconnectPolicy::Automatic => {
+              // Try to reconnect if the connection is timed out.
+              if weak_api_client.upgrade().is_some() && enable_sync.load(Ordering::SeqCst) {
+                attempt_reconnect(
+                  &ws_client,
+                  &cloned_reconnect_state,
+                  *cloned_backoff_config.load_full(),
+                  &cloned_collab_subscriptions,
+                )
+                .await;
+              }
+            },
+            ReconnectPolicy::Manual => {
+              // The embedder owns reconnection under this policy; just let it know a reconnect
+              // would be warranted instead of retrying automatically.
+              if let Some(reason) = disconnect_reason {
+                let _ = reconnect_events_tx.send(ReconnectEvent::ReconnectRequested { reason });
+              }
+            },
           },
-          ConnectState::Unauthorized => {
-            if let Some(api_client) = weak_api_client.upgrade() {
-              if let Err(err) = api_client
-                .refresh_token("websocket connect unauthorized")
-                .await
-              {
-                error!("Failed to refresh token: {}", err);
+          ConnectState::Unauthorized => match *reconnect_policy.load_full() {
+            ReconnectPolicy::Automatic => {
+              if let Some(api_client) = weak_api_client.upgrade() {
+                if let Err(err) = api_client
+                  .refresh_token("websocket connect unauthorized")
+                  .await
+                {
+                  error!("Failed to refresh token: {}", err);
+                }
               }
-            }
+            },
+            ReconnectPolicy::Manual => {
+              // Under this policy the embedder owns recovery entirely, including deciding
+              // whether/when to refresh the token; just let it know.
+              if let Some(reason) = disconnect_reason {
+                let _ = reconnect_events_tx.send(ReconnectEvent::ReconnectRequested { reason });
+              }
+            },
           },
           _ => {},
         }
@@ -331,7 +619,21 @@ fn spawn_ws_conn(
       match token_state {
         TokenState::Refresh => {
           if let Some(ws_client) = weak_ws_client.upgrade() {
-            attempt_reconnect(&ws_client, 5, &cancellation_token).await;
+            // Only a connection that's actually down needs reconnecting; a routine proactive
+            // refresh on an already-healthy socket would otherwise bounce it for no reason. A
+            // fresh token does mean the previous failures are no longer relevant, so when we do
+            // reconnect, cancel whatever backoff delay is pending and restart from the first
+            // attempt, whose jittered delay is at most `base`.
+            if !ws_client.is_connected() {
+              reconnect_state.consecutive_failures.store(0, Ordering::SeqCst);
+              attempt_reconnect(
+                &ws_client,
+                &reconnect_state,
+                *backoff_config.load_full(),
+                &collab_subscriptions,
+              )
+              .await;
+            }
           }
         },
         TokenState::Invalid => {
@@ -345,40 +647,174 @@ fn spawn_ws_conn(
   });
 }
 
-/// Attempts to reconnect a WebSocket client with a randomized delay to mitigate the thundering herd problem.
-///
-/// This function cancels any existing reconnection attempt, sets up a new cancellation token, and then
-/// attempts to reconnect after a randomized delay. The delay is set between a specified minimum and
-/// that minimum plus 10 seconds.
+/// Computes a full-jitter exponential backoff delay: `rand(0, min(cap, base * 2^attempt))`.
+fn backoff_delay(base: Duration, cap: Duration, attempt: u32) -> Duration {
+  let growth = 1u32.checked_shl(attempt.min(32)).unwrap_or(u32::MAX);
+  let upper_bound = base.checked_mul(growth).unwrap_or(cap).min(cap);
+  if upper_bound.is_zero() {
+    return upper_bound;
+  }
+  Duration::from_secs_f64(rand::thread_rng().gen_range(0.0..=upper_bound.as_secs_f64()))
+}
+
+/// Attempts to reconnect a WebSocket client, backing off exponentially with full jitter across
+/// consecutive failures to avoid a thundering herd during a prolonged server outage.
 ///
+/// This function cancels any existing reconnection attempt, sets up a new cancellation token, and
+/// then attempts to reconnect after `rand(0, min(cap, base * 2^attempt))`. The attempt counter is
+/// reset on a successful `connect()` and incremented otherwise; once `max_attempts` consecutive
+/// failures have been hit, no further automatic reconnection is scheduled.
 async fn attempt_reconnect(
   ws_client: &Arc<WSClient>,
-  minimum_delay_in_secs: u64,
-  cancellation_token: &Arc<ArcSwap<CancellationToken>>,
+  reconnect_state: &Arc<ReconnectState>,
+  backoff_config: ReconnectBackoffConfig,
+  collab_subscriptions: &Arc<CollabSubscriptionRegistry>,
 ) -> JoinHandle<()> {
-  cancellation_token.load_full().cancel();
+  reconnect_state.cancellation_token.load_full().cancel();
   let new_cancel_token = CancellationToken::new();
-  cancellation_token.store(Arc::new(new_cancel_token.clone()));
+  reconnect_state
+    .cancellation_token
+    .store(Arc::new(new_cancel_token.clone()));
+
+  let attempt = reconnect_state.consecutive_failures.load(Ordering::SeqCst);
+  if backoff_config
+    .max_attempts
+    .is_some_and(|max_attempts| attempt >= max_attempts)
+  {
+    warn!(
+      "🟡 giving up websocket reconnection after {} consecutive failures.",
+      attempt
+    );
+    return tokio::spawn(async {});
+  }
 
-  let delay_seconds = rand::thread_rng().gen_range(minimum_delay_in_secs..10);
+  let delay = backoff_delay(backoff_config.base, backoff_config.cap, attempt);
   let ws_client_clone = ws_client.clone();
+  let reconnect_state = reconnect_state.clone();
+  let collab_subscriptions = collab_subscriptions.clone();
   tokio::spawn(async move {
     select! {
         // If the new cancellation token is triggered, log cancellation
         _ = new_cancel_token.cancelled() => {
             tracing::trace!("🟢 websocket reconnection attempt cancelled.");
         },
-        _ = tokio::time::sleep(Duration::from_secs(delay_seconds)) => {
-            if let Err(e) = ws_client_clone.connect().await {
-                error!("❌ Failed to reconnect websocket: {}", e);
-            } else {
-                info!("✅ Reconnected websocket successfully.");
+        _ = tokio::time::sleep(delay) => {
+            reconnect_state.update_health(|health| health.total_reconnect_attempts += 1);
+            match ws_client_clone.connect().await {
+                Ok(_) => {
+                    info!("✅ Reconnected websocket successfully.");
+                    reconnect_state.consecutive_failures.store(0, Ordering::SeqCst);
+                    reconnect_state.update_health(|health| health.consecutive_reconnect_failures = 0);
+                    resubscribe_live_channels(&ws_client_clone, &collab_subscriptions);
+                },
+                Err(e) => {
+                    error!("❌ Failed to reconnect websocket: {}", e);
+                    let failures = reconnect_state.consecutive_failures.fetch_add(1, Ordering::SeqCst) + 1;
+                    reconnect_state.update_health(|health| health.consecutive_reconnect_failures = failures);
+                },
             }
         }
     }
   })
 }
 
+/// Re-issues `subscribe_collab` for every still-live entry in the registry, so the websocket's
+/// server-side subscription survives a reconnect instead of going silently stale. `subscribe_collab`
+/// is keyed by `object_id` on the `WSClient` side, so re-issuing it for an `object_id` the caller is
+/// still holding a channel for resumes delivery on that same, still-held `Arc<WebSocketChannel>`
+/// without the caller needing to re-subscribe itself. Entries whose subscriber has been dropped
+/// are pruned.
+fn resubscribe_live_channels(
+  ws_client: &Arc<WSClient>,
+  collab_subscriptions: &CollabSubscriptionRegistry,
+) {
+  collab_subscriptions.retain(|object_id, weak_channel| match weak_channel.upgrade() {
+    Some(_) => {
+      if let Err(err) = ws_client.subscribe_collab(object_id.clone()) {
+        warn!(
+          "Failed to re-subscribe collab {} after reconnect: {}",
+          object_id, err
+        );
+      }
+      true
+    },
+    None => false,
+  });
+}
+
+/// Spawns a task that proactively refreshes the auth token ahead of expiry, instead of waiting for
+/// a request to fail with `Unauthorized` first. Reschedules itself every time a new token arrives.
+fn spawn_proactive_token_refresh(
+  api_client: &Arc<Client>,
+  enable_sync: &Arc<AtomicBool>,
+  mut token_state_rx: TokenStateReceiver,
+) {
+  let weak_api_client = Arc::downgrade(api_client);
+  let enable_sync = enable_sync.clone();
+  let cancellation_token = Arc::new(ArcSwap::new(Arc::new(CancellationToken::new())));
+
+  // Schedule a refresh for whatever token is already present at startup.
+  if let Some(api_client) = weak_api_client.upgrade() {
+    reschedule_proactive_refresh(&api_client, &enable_sync, &cancellation_token);
+  }
+
+  tokio::spawn(async move {
+    while let Ok(token_state) = token_state_rx.recv().await {
+      if let TokenState::Refresh = token_state {
+        if let Some(api_client) = weak_api_client.upgrade() {
+          reschedule_proactive_refresh(&api_client, &enable_sync, &cancellation_token);
+        }
+      }
+    }
+  });
+}
+
+/// Cancels any pending proactive refresh and, if sync is enabled and the current token decodes
+/// with an `exp` claim, schedules a new one at `80%` of its remaining lifetime (never less than
+/// [`PROACTIVE_REFRESH_MIN_LEAD`] before expiry).
+fn reschedule_proactive_refresh(
+  api_client: &Arc<Client>,
+  enable_sync: &Arc<AtomicBool>,
+  cancellation_token: &Arc<ArcSwap<CancellationToken>>,
+) {
+  cancellation_token.load_full().cancel();
+
+  if !enable_sync.load(Ordering::SeqCst) {
+    return;
+  }
+
+  let Ok(token) = api_client.get_token() else {
+    return;
+  };
+  let Some(expires_at) = jwt_expires_at(&token) else {
+    return;
+  };
+  let Ok(remaining) = expires_at.signed_duration_since(Utc::now()).to_std() else {
+    return;
+  };
+
+  let fraction_delay =
+    Duration::from_secs_f64(remaining.as_secs_f64() * PROACTIVE_REFRESH_LIFETIME_FRACTION);
+  let delay = fraction_delay.min(remaining.saturating_sub(PROACTIVE_REFRESH_MIN_LEAD));
+
+  let new_cancel_token = CancellationToken::new();
+  cancellation_token.store(Arc::new(new_cancel_token.clone()));
+
+  let api_client = api_client.clone();
+  tokio::spawn(async move {
+    select! {
+        _ = new_cancel_token.cancelled() => {
+            tracing::trace!("🟢 proactive token refresh cancelled.");
+        },
+        _ = tokio::time::sleep(delay) => {
+            if let Err(err) = api_client.refresh_token("proactive refresh ahead of expiry").await {
+                error!("Failed to proactively refresh token: {}", err);
+            }
+        }
+    }
+  });
+}
+
 pub trait AFServer: Send + Sync + 'static {
   fn get_client(&self) -> Option<Arc<AFCloudClient>>;
   fn try_get_client(&self) -> Result<Arc<AFCloudClient>, Error>;
@@ -407,3 +843,82 @@ impl AFServer for AFServerImpl {
     }
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn backoff_delay_is_zero_when_base_is_zero() {
+    let delay = backoff_delay(Duration::ZERO, Duration::from_secs(300), 0);
+    assert_eq!(delay, Duration::ZERO);
+  }
+
+  #[test]
+  fn backoff_delay_first_attempt_is_bounded_by_base() {
+    for _ in 0..20 {
+      let delay = backoff_delay(Duration::from_secs(1), Duration::from_secs(300), 0);
+      assert!(delay <= Duration::from_secs(1));
+    }
+  }
+
+  #[test]
+  fn backoff_delay_grows_exponentially_up_to_cap() {
+    let base = Duration::from_secs(1);
+    let cap = Duration::from_secs(300);
+    for _ in 0..20 {
+      // 2^8 * 1s = 256s, still under the 300s cap.
+      let delay = backoff_delay(base, cap, 8);
+      assert!(delay <= Duration::from_secs(256));
+    }
+  }
+
+  #[test]
+  fn backoff_delay_never_exceeds_cap_for_large_attempts() {
+    let base = Duration::from_secs(1);
+    let cap = Duration::from_secs(300);
+    // Large enough that `base * 2^attempt` would overflow u32 if not clamped.
+    for attempt in [20, 32, 40, u32::MAX] {
+      for _ in 0..20 {
+        let delay = backoff_delay(base, cap, attempt);
+        assert!(delay <= cap, "delay {:?} exceeded cap for attempt {}", delay, attempt);
+      }
+    }
+  }
+
+  fn encode_jwt_payload(json: &str) -> String {
+    let payload = base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(json);
+    format!("header.{}.signature", payload)
+  }
+
+  #[test]
+  fn jwt_expires_at_reads_exp_claim() {
+    let token = encode_jwt_payload(r#"{"exp": 1700000000}"#);
+    assert_eq!(
+      jwt_expires_at(&token),
+      DateTime::from_timestamp(1700000000, 0)
+    );
+  }
+
+  #[test]
+  fn jwt_expires_at_none_without_payload_segment() {
+    assert_eq!(jwt_expires_at("not-a-jwt"), None);
+  }
+
+  #[test]
+  fn jwt_expires_at_none_for_invalid_base64() {
+    assert_eq!(jwt_expires_at("header.not-valid-base64!!!.signature"), None);
+  }
+
+  #[test]
+  fn jwt_expires_at_none_for_invalid_json() {
+    let token = encode_jwt_payload("not json");
+    assert_eq!(jwt_expires_at(&token), None);
+  }
+
+  #[test]
+  fn jwt_expires_at_none_when_exp_claim_is_missing() {
+    let token = encode_jwt_payload(r#"{"sub": "user"}"#);
+    assert_eq!(jwt_expires_at(&token), None);
+  }
+}